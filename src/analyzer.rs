@@ -1,16 +1,21 @@
 use std::collections::HashMap;
 use git2::{Repository, DiffOptions};
 use prettytable::{Table, row};
-use crate::models::AuthorStats;
+use crate::models::{AuthorStats, AuthorStatsRow, OutputFormat};
+use crate::utils::DateRange;
 
-pub fn analyze_repo(repo: &Repository) {
+pub fn analyze_repo(repo: &Repository, format: OutputFormat, date_range: DateRange) {
     let mut author_stats: HashMap<String, AuthorStats> = HashMap::new();
     let mut revwalk: git2::Revwalk<'_> = repo.revwalk().expect("Failed to get revwalk");
     revwalk.push_head().expect("Failed to push head");
-    
+
     for commit_id in revwalk {
         if let Ok(oid) = commit_id {
             if let Ok(commit) = repo.find_commit(oid) {
+                if !date_range.contains(commit.time().seconds()) {
+                    continue;
+                }
+
                 let author: String = commit.author().name().unwrap_or("Unknown").to_string();
                 let parent: Option<git2::Commit<'_>> = commit.parent(0).ok();
 
@@ -39,25 +44,78 @@ pub fn analyze_repo(repo: &Repository) {
     let total_contributions: usize = author_stats.values().map(|s| s.lines_added + s.lines_deleted).sum();
     let mut stats_vec: Vec<_> = author_stats.into_iter().collect();
     stats_vec.sort_by(|a: &(String, AuthorStats), b: &(String, AuthorStats)| b.1.commits.cmp(&a.1.commits));
-    
+
+    let rows: Vec<AuthorStatsRow> = stats_vec
+        .into_iter()
+        .map(|(author, stats)| {
+            let contribution_pct: f64 = if total_contributions > 0 {
+                (stats.lines_added + stats.lines_deleted) as f64 / total_contributions as f64 * 100.0
+            } else {
+                0.0
+            };
+            AuthorStatsRow {
+                author,
+                commits: stats.commits,
+                lines_added: stats.lines_added,
+                lines_deleted: stats.lines_deleted,
+                contribution_pct,
+            }
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Table => print_table(&rows),
+        OutputFormat::Markdown => print_markdown(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_csv(&rows),
+    }
+}
+
+fn print_table(rows: &[AuthorStatsRow]) {
     let mut table = Table::new();
     table.add_row(row!["Author", "Commits", "Lines Added", "Lines Deleted", "Contribution %"]);
-    
-    for (author, stats) in stats_vec {
-        let contribution: f64 = if total_contributions > 0 {
-            (stats.lines_added + stats.lines_deleted) as f64 / total_contributions as f64 * 100.0
-        } else {
-            0.0
-        };
-        
+
+    for row in rows {
         table.add_row(row![
-            author, 
-            stats.commits.to_string(), 
-            stats.lines_added.to_string(), 
-            stats.lines_deleted.to_string(), 
-            format!("{:.2}%", contribution)
+            row.author,
+            row.commits.to_string(),
+            row.lines_added.to_string(),
+            row.lines_deleted.to_string(),
+            format!("{:.2}%", row.contribution_pct)
         ]);
     }
-    
+
     table.printstd();
+}
+
+fn print_markdown(rows: &[AuthorStatsRow]) {
+    println!("| Author | Commits | Lines Added | Lines Deleted | Contribution % |");
+    println!("| --- | --- | --- | --- | --- |");
+    for row in rows {
+        println!(
+            "| {} | {} | {} | {} | {:.2}% |",
+            row.author, row.commits, row.lines_added, row.lines_deleted, row.contribution_pct
+        );
+    }
+}
+
+fn print_json(rows: &[AuthorStatsRow]) {
+    match serde_json::to_string_pretty(rows) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("❌ Failed to serialize stats to JSON: {}", e),
+    }
+}
+
+fn print_csv(rows: &[AuthorStatsRow]) {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for row in rows {
+        if let Err(e) = writer.serialize(row) {
+            eprintln!("❌ Failed to serialize stats to CSV: {}", e);
+            return;
+        }
+    }
+    match writer.into_inner().map(String::from_utf8) {
+        Ok(Ok(csv)) => print!("{}", csv),
+        _ => eprintln!("❌ Failed to render CSV output"),
+    }
 }
\ No newline at end of file
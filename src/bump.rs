@@ -0,0 +1,207 @@
+use colored::*;
+use git2::Repository;
+use crate::conventional;
+use crate::repo::find_latest_tag;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BumpLevel {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    /// Parse a `major.minor.patch` version, tolerating a leading `v`.
+    fn parse(tag: &str) -> Option<Version> {
+        let trimmed = tag.strip_prefix('v').unwrap_or(tag);
+        let mut parts = trimmed.splitn(3, '.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next()?.parse().ok()?;
+        Some(Version { major, minor, patch })
+    }
+
+    fn bump(&self, level: BumpLevel) -> Version {
+        match level {
+            BumpLevel::Major => Version { major: self.major + 1, minor: 0, patch: 0 },
+            BumpLevel::Minor => Version { major: self.major, minor: self.minor + 1, patch: 0 },
+            BumpLevel::Patch => Version { major: self.major, minor: self.minor, patch: self.patch + 1 },
+            BumpLevel::None => *self,
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Recommend the next SemVer version from commits since the last tag.
+pub fn recommend_bump(repo: &Repository) {
+    let (tag_name, tag_commit) = match find_latest_tag(repo) {
+        Some(found) => found,
+        None => {
+            println!("{}", "❌ No tags found to compute a bump from.".red());
+            return;
+        }
+    };
+
+    let current = match Version::parse(&tag_name) {
+        Some(version) => version,
+        None => {
+            println!("{}", format!("❌ Tag '{}' is not a valid SemVer tag.", tag_name).red());
+            return;
+        }
+    };
+
+    let mut revwalk = repo.revwalk().expect("Failed to create revwalk");
+    revwalk.push_head().expect("Failed to push head");
+    revwalk.hide(tag_commit).expect("Failed to hide tag commit");
+
+    let mut level = BumpLevel::None;
+    let mut breaking_summaries = Vec::new();
+    let mut feature_summaries = Vec::new();
+    let mut fix_summaries = Vec::new();
+
+    for commit_id in revwalk {
+        if let Ok(oid) = commit_id {
+            if let Ok(commit) = repo.find_commit(oid) {
+                let message = commit.message().unwrap_or("").to_string();
+                if let Some(cc) = conventional::parse(&message) {
+                    let summary = message.lines().next().unwrap_or("").to_string();
+                    if cc.breaking {
+                        level = level.max(BumpLevel::Major);
+                        breaking_summaries.push(summary);
+                    } else if cc.kind == "feat" {
+                        level = level.max(BumpLevel::Minor);
+                        feature_summaries.push(summary);
+                    } else if cc.kind == "fix" || cc.kind == "perf" {
+                        level = level.max(BumpLevel::Patch);
+                        fix_summaries.push(summary);
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Current version: {}", tag_name.green());
+
+    if level == BumpLevel::None {
+        println!("{}", "No release needed — no feat/fix/perf/breaking commits since the last tag.".yellow());
+        return;
+    }
+
+    let next = current.bump(level);
+    let label = match level {
+        BumpLevel::Major => "major",
+        BumpLevel::Minor => "minor",
+        BumpLevel::Patch => "patch",
+        BumpLevel::None => unreachable!(),
+    };
+    println!("Recommended bump: {} \u{2192} {}", label.green(), next.to_string().green());
+
+    if !breaking_summaries.is_empty() {
+        println!("\nBreaking changes:");
+        for summary in &breaking_summaries {
+            println!("  - {}", summary);
+        }
+    }
+    if !feature_summaries.is_empty() {
+        println!("\nFeatures:");
+        for summary in &feature_summaries {
+            println!("  - {}", summary);
+        }
+    }
+    if !fix_summaries.is_empty() {
+        println!("\nFixes & performance improvements:");
+        for summary in &fix_summaries {
+            println!("  - {}", summary);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_version_with_leading_v() {
+        let version = Version::parse("v1.2.3").unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+    }
+
+    #[test]
+    fn parses_version_without_leading_v() {
+        let version = Version::parse("1.2.3").unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+    }
+
+    #[test]
+    fn rejects_missing_patch_component() {
+        assert!(Version::parse("v1.2").is_none());
+    }
+
+    #[test]
+    fn rejects_prerelease_suffix() {
+        assert!(Version::parse("v1.2.3-beta").is_none());
+    }
+
+    #[test]
+    fn bump_precedence_prefers_major_over_minor_and_patch() {
+        let mut level = BumpLevel::Patch;
+        level = level.max(BumpLevel::Minor);
+        level = level.max(BumpLevel::Major);
+        assert_eq!(level, BumpLevel::Major);
+    }
+
+    #[test]
+    fn bump_precedence_prefers_minor_over_patch() {
+        let mut level = BumpLevel::Patch;
+        level = level.max(BumpLevel::Minor);
+        assert_eq!(level, BumpLevel::Minor);
+    }
+
+    #[test]
+    fn bump_precedence_prefers_patch_over_none() {
+        let mut level = BumpLevel::None;
+        level = level.max(BumpLevel::Patch);
+        assert_eq!(level, BumpLevel::Patch);
+    }
+
+    #[test]
+    fn bump_major_resets_minor_and_patch() {
+        let version = Version { major: 1, minor: 4, patch: 7 };
+        let bumped = version.bump(BumpLevel::Major);
+        assert_eq!((bumped.major, bumped.minor, bumped.patch), (2, 0, 0));
+    }
+
+    #[test]
+    fn bump_minor_resets_patch() {
+        let version = Version { major: 1, minor: 4, patch: 7 };
+        let bumped = version.bump(BumpLevel::Minor);
+        assert_eq!((bumped.major, bumped.minor, bumped.patch), (1, 5, 0));
+    }
+
+    #[test]
+    fn bump_patch_increments_patch_only() {
+        let version = Version { major: 1, minor: 4, patch: 7 };
+        let bumped = version.bump(BumpLevel::Patch);
+        assert_eq!((bumped.major, bumped.minor, bumped.patch), (1, 4, 8));
+    }
+
+    #[test]
+    fn bump_none_leaves_version_unchanged() {
+        let version = Version { major: 1, minor: 4, patch: 7 };
+        let bumped = version.bump(BumpLevel::None);
+        assert_eq!((bumped.major, bumped.minor, bumped.patch), (1, 4, 7));
+    }
+}
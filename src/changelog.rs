@@ -1,13 +1,16 @@
+use std::collections::BTreeMap;
 use std::fs;
 use colored::*;
 use git2::{Repository, DiffOptions};
-use crate::models::CommitInfo;
+use serde::Serialize;
+use crate::conventional::{self, ConventionalCommit};
+use crate::models::{CommitInfo, OutputFormat};
 use crate::repo::{tag_exists, get_commit_from_tag};
-use crate::utils::format_time;
+use crate::utils::{format_relative_time, format_time, DateRange};
 
 
 /// Generate a changelog between two tags
-pub fn generate_changelog(repo: &Repository, from_tag: &str, to_tag: &str, output: &Option<String>) {
+pub fn generate_changelog(repo: &Repository, from_tag: &str, to_tag: &str, output: &Option<String>, format: OutputFormat, date_range: DateRange, relative: bool) {
     let title: String = format!("Changelog from {} to {}", from_tag, to_tag);
     // Check if tags exist
     if !tag_exists(repo, from_tag) {
@@ -37,46 +40,83 @@ pub fn generate_changelog(repo: &Repository, from_tag: &str, to_tag: &str, outpu
         }
     };
     
-    println!("Generating changelog from '{}' to '{}'...", from_tag.green(), to_tag.green());
+    if format == OutputFormat::Table {
+        println!("Generating changelog from '{}' to '{}'...", from_tag.green(), to_tag.green());
+    }
     // Get commits between the tags
     let mut commits = Vec::new();
     let mut revwalk = repo.revwalk().expect("Failed to create revwalk");
     revwalk.push(to_commit_id).expect("Failed to push to_tag");
     revwalk.hide(from_commit_id).expect("Failed to hide from_tag");
-    
+
+    // When a date range narrows the commit list, the file-change stats must
+    // be narrowed to match: summed from each selected commit's own diff
+    // against its parent, rather than the full from_tag..to_tag diff below.
+    let mut ranged_files_changed = 0usize;
+    let mut ranged_insertions = 0usize;
+    let mut ranged_deletions = 0usize;
+
     for commit_id in revwalk {
         if let Ok(oid) = commit_id {
             if let Ok(commit) = repo.find_commit(oid) {
+                if !date_range.contains(commit.time().seconds()) {
+                    continue;
+                }
+
+                if !date_range.is_unbounded() {
+                    let parent_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+                    let commit_tree = commit.tree().ok();
+                    if let Ok(commit_diff) = repo.diff_tree_to_tree(parent_tree.as_ref(), commit_tree.as_ref(), None) {
+                        if let Ok(commit_stats) = commit_diff.stats() {
+                            ranged_files_changed += commit_stats.files_changed();
+                            ranged_insertions += commit_stats.insertions();
+                            ranged_deletions += commit_stats.deletions();
+                        }
+                    }
+                }
+
+                let date = if relative {
+                    format_relative_time(&commit.time())
+                } else {
+                    format_time(&commit.time())
+                };
+
                 let commit_info = CommitInfo {
                     hash: oid.to_string(),
                     author: commit.author().name().unwrap_or("Unknown").to_string(),
-                    date: format_time(&commit.time()),
+                    date,
                     message: commit.message().unwrap_or("").to_string(),
                 };
                 commits.push(commit_info);
             }
         }
     }
-    
+
     // Calculate file changes
-    let from_commit = repo.find_commit(from_commit_id).expect("Failed to find from_commit");
-    let to_commit = repo.find_commit(to_commit_id).expect("Failed to find to_commit");
-    
-    let from_tree = from_commit.tree().expect("Failed to get from_tree");
-    let to_tree = to_commit.tree().expect("Failed to get to_tree");
-    
-    let mut diff_opts = DiffOptions::new();
-    let diff = repo.diff_tree_to_tree(Some(&from_tree), Some(&to_tree), Some(&mut diff_opts))
-        .expect("Failed to diff trees");
-    
-    let stats = diff.stats().expect("Failed to get diff stats");
-    let files_changed = stats.files_changed();
-    let insertions = stats.insertions();
-    let deletions = stats.deletions();
-    
-    // Format the changelog
-    let changelog = format_changelog(title, &commits, files_changed, insertions, deletions);
-    
+    let (files_changed, insertions, deletions) = if date_range.is_unbounded() {
+        let from_commit = repo.find_commit(from_commit_id).expect("Failed to find from_commit");
+        let to_commit = repo.find_commit(to_commit_id).expect("Failed to find to_commit");
+
+        let from_tree = from_commit.tree().expect("Failed to get from_tree");
+        let to_tree = to_commit.tree().expect("Failed to get to_tree");
+
+        let mut diff_opts = DiffOptions::new();
+        let diff = repo.diff_tree_to_tree(Some(&from_tree), Some(&to_tree), Some(&mut diff_opts))
+            .expect("Failed to diff trees");
+
+        let stats = diff.stats().expect("Failed to get diff stats");
+        (stats.files_changed(), stats.insertions(), stats.deletions())
+    } else {
+        (ranged_files_changed, ranged_insertions, ranged_deletions)
+    };
+
+    // Format the changelog in the requested output format
+    let changelog = match format {
+        OutputFormat::Json => format_changelog_json(title, &commits, files_changed, insertions, deletions),
+        OutputFormat::Csv => format_changelog_csv(&commits),
+        OutputFormat::Table | OutputFormat::Markdown => format_changelog(title, &commits, files_changed, insertions, deletions),
+    };
+
     // Save to file or print to screen
     match output {
         Some(file_path) => {
@@ -92,42 +132,66 @@ pub fn generate_changelog(repo: &Repository, from_tag: &str, to_tag: &str, outpu
     }
 }
 
+/// Commits grouped by Conventional Commits type, with unparsed ones falling into `others`.
+struct Categorized<'a> {
+    features: Vec<(&'a CommitInfo, ConventionalCommit)>,
+    fixes: Vec<(&'a CommitInfo, ConventionalCommit)>,
+    performance: Vec<(&'a CommitInfo, ConventionalCommit)>,
+    others: Vec<&'a CommitInfo>,
+    breaking: Vec<(&'a CommitInfo, String)>,
+}
+
+fn categorize(commits: &[CommitInfo]) -> Categorized<'_> {
+    let mut categorized = Categorized {
+        features: Vec::new(),
+        fixes: Vec::new(),
+        performance: Vec::new(),
+        others: Vec::new(),
+        breaking: Vec::new(),
+    };
+
+    for commit in commits {
+        match conventional::parse(&commit.message) {
+            Some(cc) => {
+                if cc.breaking {
+                    let description = cc.breaking_description.clone().unwrap_or_else(|| cc.description.clone());
+                    categorized.breaking.push((commit, description));
+                }
+                match cc.kind.as_str() {
+                    "feat" => categorized.features.push((commit, cc)),
+                    "fix" => categorized.fixes.push((commit, cc)),
+                    "perf" => categorized.performance.push((commit, cc)),
+                    _ => categorized.others.push(commit),
+                }
+            }
+            None => categorized.others.push(commit),
+        }
+    }
+
+    categorized
+}
+
 /// Format the changelog into a readable string
 pub fn format_changelog(title: String,commits: &[CommitInfo], files_changed: usize, insertions: usize, deletions: usize) -> String {
     let mut result = String::new();
-    
+
     // Add header
     result.push_str(format!("# {}\n\n", title).as_str());
-    
+
     // Add statistics section
     result.push_str("## Statistics\n\n");
     result.push_str(&format!("- Files changed: {}\n", files_changed));
     result.push_str(&format!("- Lines added: {}\n", insertions));
     result.push_str(&format!("- Lines deleted: {}\n", deletions));
     result.push_str(&format!("- Total commits: {}\n\n", commits.len()));
-    
-    // Group commits by type (assuming conventional commit format)
-    let mut features = Vec::new();
-    let mut fixes = Vec::new();
-    let mut others = Vec::new();
-    
-    for commit in commits {
-        let msg = &commit.message;
-        if msg.starts_with("Merged PR") || msg.starts_with("feature") || msg.starts_with("task") {
-            features.push(commit);
-        } else if msg.starts_with("fix") || msg.starts_with("bug") {
-            fixes.push(commit);
-        } else {
-            others.push(commit);
-        }
-    }
-    
-    // Add sections for each commit type
-    if !features.is_empty() {
-        result.push_str("## New Features\n\n");
-        for commit in &features {
-            result.push_str(&format!("- {} ({})\n  _by {} on {}_\n", 
-                commit.message.lines().next().unwrap_or(""),
+
+    let categorized = categorize(commits);
+
+    if !categorized.breaking.is_empty() {
+        result.push_str("### \u{26a0} BREAKING CHANGES\n\n");
+        for (commit, description) in &categorized.breaking {
+            result.push_str(&format!("- {} ({})\n  _by {} on {}_\n",
+                description,
                 &commit.hash[..7],
                 commit.author,
                 commit.date
@@ -135,31 +199,166 @@ pub fn format_changelog(title: String,commits: &[CommitInfo], files_changed: usi
         }
         result.push('\n');
     }
-    
-    if !fixes.is_empty() {
-        result.push_str("## Bug Fixes\n\n");
-        for commit in &fixes {
-            result.push_str(&format!("- {} ({})\n  _by {} on {}_\n", 
+
+    write_section(&mut result, "### Features", &categorized.features);
+    write_section(&mut result, "### Bug Fixes", &categorized.fixes);
+    write_section(&mut result, "### Performance", &categorized.performance);
+
+    if !categorized.others.is_empty() {
+        result.push_str("### Other Changes\n\n");
+        for commit in &categorized.others {
+            result.push_str(&format!("- {} ({})\n  _by {} on {}_\n",
                 commit.message.lines().next().unwrap_or(""),
                 &commit.hash[..7],
                 commit.author,
                 commit.date
             ));
         }
-        result.push('\n');
     }
-    
-    if !others.is_empty() {
-        result.push_str("## Other Changes\n\n");
-        for commit in &others {
-            result.push_str(&format!("- {} ({})\n  _by {} on {}_\n", 
-                commit.message.lines().next().unwrap_or(""),
+
+    result
+}
+
+#[derive(Serialize)]
+struct ChangelogEntryJson {
+    hash: String,
+    author: String,
+    date: String,
+    message: String,
+    scope: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ChangelogStatsJson {
+    files_changed: usize,
+    insertions: usize,
+    deletions: usize,
+    total_commits: usize,
+}
+
+#[derive(Serialize)]
+struct ChangelogReportJson {
+    title: String,
+    stats: ChangelogStatsJson,
+    breaking_changes: Vec<ChangelogEntryJson>,
+    features: Vec<ChangelogEntryJson>,
+    bug_fixes: Vec<ChangelogEntryJson>,
+    performance: Vec<ChangelogEntryJson>,
+    other_changes: Vec<ChangelogEntryJson>,
+}
+
+fn to_entry(commit: &CommitInfo, scope: Option<String>) -> ChangelogEntryJson {
+    ChangelogEntryJson {
+        hash: commit.hash.clone(),
+        author: commit.author.clone(),
+        date: commit.date.clone(),
+        message: commit.message.lines().next().unwrap_or("").to_string(),
+        scope,
+    }
+}
+
+/// Format the changelog as a structured JSON report, suitable for posting to a release API.
+pub fn format_changelog_json(title: String, commits: &[CommitInfo], files_changed: usize, insertions: usize, deletions: usize) -> String {
+    let categorized = categorize(commits);
+
+    let report = ChangelogReportJson {
+        title,
+        stats: ChangelogStatsJson {
+            files_changed,
+            insertions,
+            deletions,
+            total_commits: commits.len(),
+        },
+        breaking_changes: categorized.breaking.iter().map(|(commit, _)| to_entry(commit, None)).collect(),
+        features: categorized.features.iter().map(|(commit, cc)| to_entry(commit, cc.scope.clone())).collect(),
+        bug_fixes: categorized.fixes.iter().map(|(commit, cc)| to_entry(commit, cc.scope.clone())).collect(),
+        performance: categorized.performance.iter().map(|(commit, cc)| to_entry(commit, cc.scope.clone())).collect(),
+        other_changes: categorized.others.iter().map(|commit| to_entry(commit, None)).collect(),
+    };
+
+    serde_json::to_string_pretty(&report).unwrap_or_else(|e| format!("{{\"error\": \"failed to serialize changelog: {}\"}}", e))
+}
+
+#[derive(Serialize)]
+struct ChangelogCsvRow {
+    section: &'static str,
+    hash: String,
+    author: String,
+    date: String,
+    message: String,
+    scope: String,
+}
+
+fn to_csv_row(section: &'static str, commit: &CommitInfo, scope: Option<String>) -> ChangelogCsvRow {
+    ChangelogCsvRow {
+        section,
+        hash: commit.hash.clone(),
+        author: commit.author.clone(),
+        date: commit.date.clone(),
+        message: commit.message.lines().next().unwrap_or("").to_string(),
+        scope: scope.unwrap_or_default(),
+    }
+}
+
+/// Format the changelog as a flat CSV of commits with a `section` column: one row per
+/// commit, except that a breaking commit gets a `breaking` row as well as the row of its type.
+pub fn format_changelog_csv(commits: &[CommitInfo]) -> String {
+    let categorized = categorize(commits);
+
+    let mut rows = Vec::new();
+    for (commit, _) in &categorized.breaking {
+        rows.push(to_csv_row("breaking", commit, None));
+    }
+    for (commit, cc) in &categorized.features {
+        rows.push(to_csv_row("feature", commit, cc.scope.clone()));
+    }
+    for (commit, cc) in &categorized.fixes {
+        rows.push(to_csv_row("fix", commit, cc.scope.clone()));
+    }
+    for (commit, cc) in &categorized.performance {
+        rows.push(to_csv_row("performance", commit, cc.scope.clone()));
+    }
+    for commit in &categorized.others {
+        rows.push(to_csv_row("other", commit, None));
+    }
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for row in &rows {
+        if let Err(e) = writer.serialize(row) {
+            return format!("error: failed to serialize changelog to CSV: {}", e);
+        }
+    }
+    match writer.into_inner().map(String::from_utf8) {
+        Ok(Ok(csv)) => csv,
+        _ => "error: failed to render changelog CSV".to_string(),
+    }
+}
+
+/// Render a section of conventional commits, sub-grouped by scope (unscoped entries listed first).
+fn write_section(result: &mut String, heading: &str, entries: &[(&CommitInfo, ConventionalCommit)]) {
+    if entries.is_empty() {
+        return;
+    }
+
+    result.push_str(&format!("{}\n\n", heading));
+
+    let mut by_scope: BTreeMap<Option<String>, Vec<&(&CommitInfo, ConventionalCommit)>> = BTreeMap::new();
+    for entry in entries {
+        by_scope.entry(entry.1.scope.clone()).or_default().push(entry);
+    }
+
+    for (scope, group) in &by_scope {
+        if let Some(scope) = scope {
+            result.push_str(&format!("#### {}\n\n", scope));
+        }
+        for (commit, cc) in group {
+            result.push_str(&format!("- {} ({})\n  _by {} on {}_\n",
+                cc.description,
                 &commit.hash[..7],
                 commit.author,
                 commit.date
             ));
         }
+        result.push('\n');
     }
-    
-    result
 }
\ No newline at end of file
@@ -0,0 +1,154 @@
+/// Commit types recognized by the Conventional Commits spec we support.
+const COMMIT_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// A commit message parsed as a Conventional Commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+    pub kind: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+    pub body: String,
+    pub breaking_description: Option<String>,
+}
+
+/// Parse a commit message as `type(scope)?(!)?: description`, returning `None` if it doesn't match.
+pub fn parse(msg: &str) -> Option<ConventionalCommit> {
+    let mut lines = msg.lines();
+    let header = lines.next()?.trim();
+    let body = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+
+    let colon_pos = header.find(": ")?;
+    let (prefix, rest) = header.split_at(colon_pos);
+    let description = rest[2..].trim().to_string();
+    if description.is_empty() {
+        return None;
+    }
+
+    let (prefix, mut breaking) = match prefix.strip_suffix('!') {
+        Some(stripped) => (stripped, true),
+        None => (prefix, false),
+    };
+
+    let (kind, scope) = match prefix.find('(') {
+        Some(open) if prefix.ends_with(')') => {
+            let kind = &prefix[..open];
+            let scope = &prefix[open + 1..prefix.len() - 1];
+            if scope.is_empty() {
+                return None;
+            }
+            (kind, Some(scope.to_string()))
+        }
+        Some(_) => return None,
+        None => (prefix, None),
+    };
+
+    if !COMMIT_TYPES.contains(&kind) {
+        return None;
+    }
+
+    let mut breaking_description = None;
+    for (token, value) in parse_footers(&body) {
+        if token == "BREAKING CHANGE" || token == "BREAKING-CHANGE" {
+            breaking = true;
+            breaking_description = Some(value);
+        }
+    }
+
+    Some(ConventionalCommit {
+        kind: kind.to_string(),
+        scope,
+        breaking,
+        description,
+        body,
+        breaking_description,
+    })
+}
+
+/// Scan a commit body for footers of the form `TOKEN: value` or `TOKEN #value`.
+fn parse_footers(body: &str) -> Vec<(String, String)> {
+    let mut footers = Vec::new();
+
+    for line in body.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(idx) = line.find(": ") {
+            let token = &line[..idx];
+            if is_footer_token(token) {
+                footers.push((token.to_string(), line[idx + 2..].trim().to_string()));
+                continue;
+            }
+        }
+
+        if let Some(idx) = line.find(" #") {
+            let token = &line[..idx];
+            if is_footer_token(token) {
+                footers.push((token.to_string(), line[idx + 2..].trim().to_string()));
+            }
+        }
+    }
+
+    footers
+}
+
+fn is_footer_token(token: &str) -> bool {
+    !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c.is_ascii_digit() || c == '-' || c == ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_feature() {
+        let cc = parse("feat: add login page").unwrap();
+        assert_eq!(cc.kind, "feat");
+        assert_eq!(cc.scope, None);
+        assert!(!cc.breaking);
+        assert_eq!(cc.description, "add login page");
+    }
+
+    #[test]
+    fn parses_scope_and_bang() {
+        let cc = parse("fix(auth)!: reject expired tokens").unwrap();
+        assert_eq!(cc.kind, "fix");
+        assert_eq!(cc.scope, Some("auth".to_string()));
+        assert!(cc.breaking);
+    }
+
+    #[test]
+    fn parses_breaking_change_footer() {
+        let msg = "refactor(api): drop legacy endpoint\n\nBREAKING CHANGE: /v1/users is removed";
+        let cc = parse(msg).unwrap();
+        assert!(cc.breaking);
+        assert_eq!(cc.breaking_description, Some("/v1/users is removed".to_string()));
+    }
+
+    #[test]
+    fn recognizes_mixed_case_footers() {
+        let msg = "fix(auth): reject expired tokens\n\nReviewed-by: Alice\nCo-authored-by: Bob";
+        let body = parse(msg).unwrap().body;
+        assert_eq!(parse_footers(&body), vec![
+            ("Reviewed-by".to_string(), "Alice".to_string()),
+            ("Co-authored-by".to_string(), "Bob".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn rejects_unknown_type() {
+        assert!(parse("wip: half-baked idea").is_none());
+    }
+
+    #[test]
+    fn rejects_missing_description() {
+        assert!(parse("feat:").is_none());
+    }
+}
@@ -2,12 +2,21 @@ mod models;
 mod repo;
 mod analyzer;
 mod changelog;
+mod conventional;
+mod bump;
+mod ownership;
+mod status;
+#[cfg(test)]
+mod test_support;
+mod track;
 mod utils;
 
 use std::path::Path;
 use colored::*;
 use git2::Repository;
 use clap::{Parser, Subcommand};
+use models::OutputFormat;
+use utils::DateRange;
 
 /// Program to analyze the contribution statistics of each author in a Git repository
 #[derive(Parser, Debug)]
@@ -17,6 +26,22 @@ struct Args {
     #[arg(short, long, default_value = ".")]
     path: String,
 
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    format: OutputFormat,
+
+    /// Only include commits on or after this date (YYYY-MM-DD); applies to the default analysis and changelog
+    #[arg(long, global = true)]
+    since: Option<String>,
+
+    /// Only include commits on or before this date (YYYY-MM-DD); applies to the default analysis and changelog
+    #[arg(long, global = true)]
+    until: Option<String>,
+
+    /// Render dates as human-relative strings (e.g. "3 days ago")
+    #[arg(long, global = true)]
+    relative: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -36,26 +61,72 @@ enum Commands {
         /// Output file (optional)
         #[arg(short, long)]
         output: Option<String>,
-    }
+    },
+    /// Recommend the next SemVer version from commits since the last tag
+    Bump,
+    /// Report blame-based code ownership and bus-factor risk
+    Ownership {
+        /// Flag files where a single author owns more than this percentage
+        #[arg(short, long, default_value_t = 80.0)]
+        threshold: f64,
+    },
+    /// Report working-tree state, branch, and ahead/behind counts
+    Status,
+    /// Record per-commit repository-health metrics to a TOML history file
+    Track {
+        /// TOML file to append metric snapshots to
+        #[arg(short, long, default_value = "metrics.toml")]
+        output: String,
+
+        /// Print recorded metrics and their deltas instead of recording new ones
+        #[arg(short, long)]
+        report: bool,
+    },
 }
 
 fn main() {
     let args: Args = Args::parse();
     let repo_path: &Path = Path::new(&args.path);
 
+    let date_range = match DateRange::parse(&args.since, &args.until) {
+        Ok(range) => range,
+        Err(e) => {
+            println!("{}", format!("❌ {}", e).red());
+            return;
+        }
+    };
+
     match Repository::open(repo_path) {
         Ok(repo) => {
             match &args.command {
                 Some(Commands::Changelog { from_tag, to_tag, output }) => {
-                    changelog::generate_changelog(&repo, from_tag, to_tag, output);
+                    changelog::generate_changelog(&repo, from_tag, to_tag, output, args.format, date_range, args.relative);
+                },
+                Some(Commands::Bump) => {
+                    bump::recommend_bump(&repo);
+                },
+                Some(Commands::Ownership { threshold }) => {
+                    ownership::analyze_ownership(&repo, *threshold);
+                },
+                Some(Commands::Status) => {
+                    status::show_status(&repo);
+                },
+                Some(Commands::Track { output, report }) => {
+                    if *report {
+                        track::report(output);
+                    } else {
+                        track::track(&repo, output);
+                    }
                 },
                 None => {
                     if repo.is_empty().unwrap_or(true) {
                         println!("{}", "Repository is empty. No commits to analyze.".yellow());
                     } else {
-                        let dirname: &str = repo_path.file_name().and_then(|name| name.to_str()).unwrap_or("");
-                        println!("✅ Analyzing repository: {}", dirname.green());
-                        analyzer::analyze_repo(&repo);
+                        if args.format == OutputFormat::Table {
+                            let dirname: &str = repo_path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+                            println!("✅ Analyzing repository: {}", dirname.green());
+                        }
+                        analyzer::analyze_repo(&repo, args.format, date_range);
                     }
                 }
             }
@@ -1,12 +1,50 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct AuthorStats {
     pub commits: usize,
     pub lines_added: usize,
     pub lines_deleted: usize,
 }
 
+#[derive(Debug, Clone, Serialize)]
 pub struct CommitInfo {
     pub hash: String,
     pub author: String,
     pub date: String,
     pub message: String,
+}
+
+/// A single author's row in the analyzer's output, flattened for
+/// machine-readable formats (JSON/CSV).
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorStatsRow {
+    pub author: String,
+    pub commits: usize,
+    pub lines_added: usize,
+    pub lines_deleted: usize,
+    pub contribution_pct: f64,
+}
+
+/// Output format shared by the analyzer and changelog commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Markdown => "markdown",
+        };
+        write!(f, "{}", label)
+    }
 }
\ No newline at end of file
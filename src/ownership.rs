@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::path::Path;
+use colored::*;
+use git2::{ObjectType, Repository, TreeWalkMode, TreeWalkResult};
+
+/// Report blame-based code ownership and bus-factor risk at HEAD.
+pub fn analyze_ownership(repo: &Repository, threshold: f64) {
+    let head_tree = match repo.head().and_then(|head| head.peel_to_commit()).and_then(|commit| commit.tree()) {
+        Ok(tree) => tree,
+        Err(e) => {
+            println!("{}", format!("❌ Failed to read HEAD tree: {}", e).red());
+            return;
+        }
+    };
+
+    let mut paths = Vec::new();
+    let walk_result = head_tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+        if entry.kind() == Some(ObjectType::Blob) {
+            if let Some(name) = entry.name() {
+                paths.push(format!("{}{}", root, name));
+            }
+        }
+        TreeWalkResult::Ok
+    });
+    if let Err(e) = walk_result {
+        println!("{}", format!("❌ Failed to walk HEAD tree: {}", e).red());
+        return;
+    }
+
+    let mut author_totals: HashMap<String, usize> = HashMap::new();
+    let mut file_reports: Vec<(String, HashMap<String, usize>, usize)> = Vec::new();
+    let mut total_lines = 0usize;
+
+    for path in &paths {
+        let blame = match repo.blame_file(Path::new(path), None) {
+            Ok(blame) => blame,
+            Err(_) => continue,
+        };
+
+        let mut per_author: HashMap<String, usize> = HashMap::new();
+        let mut file_lines = 0usize;
+        for hunk in blame.iter() {
+            let author = hunk.final_signature().name().unwrap_or("Unknown").to_string();
+            let lines = hunk.lines_in_hunk();
+            *per_author.entry(author.clone()).or_insert(0) += lines;
+            *author_totals.entry(author).or_insert(0) += lines;
+            file_lines += lines;
+        }
+
+        total_lines += file_lines;
+        file_reports.push((path.clone(), per_author, file_lines));
+    }
+
+    if total_lines == 0 {
+        println!("{}", "No blameable lines found.".yellow());
+        return;
+    }
+
+    println!("## Per-file ownership\n");
+    let mut hotspots = Vec::new();
+    for (path, per_author, file_lines) in &file_reports {
+        if *file_lines == 0 {
+            continue;
+        }
+
+        let mut authors: Vec<_> = per_author.iter().collect();
+        authors.sort_by(|a, b| b.1.cmp(a.1));
+
+        println!("- {} ({} lines)", path, file_lines);
+        for (author, lines) in &authors {
+            let pct = **lines as f64 / *file_lines as f64 * 100.0;
+            println!("    {} — {:.1}% ({} lines)", author, pct, lines);
+        }
+
+        let (top_author, top_lines) = authors[0];
+        let top_pct = *top_lines as f64 / *file_lines as f64 * 100.0;
+        if top_pct > threshold {
+            hotspots.push((path.clone(), top_author.clone(), top_pct));
+        }
+    }
+
+    println!("\n## Per-author ownership\n");
+    let mut author_vec: Vec<_> = author_totals.into_iter().collect();
+    author_vec.sort_by_key(|&(_, lines)| std::cmp::Reverse(lines));
+    for (author, lines) in &author_vec {
+        let pct = *lines as f64 / total_lines as f64 * 100.0;
+        println!("- {} — {:.2}% ({} lines)", author, pct, lines);
+    }
+
+    let mut cumulative = 0usize;
+    let mut bus_factor = 0usize;
+    for (_, lines) in &author_vec {
+        cumulative += lines;
+        bus_factor += 1;
+        if cumulative as f64 / total_lines as f64 > 0.5 {
+            break;
+        }
+    }
+    println!("\nBus factor: {}", bus_factor.to_string().green());
+
+    if !hotspots.is_empty() {
+        println!("\n{}", format!("⚠ Knowledge-risk hotspots (single author owns > {:.0}%):", threshold).yellow());
+        for (path, author, pct) in &hotspots {
+            println!("  - {} — {} owns {:.1}%", path, author, pct);
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use git2::{Repository, ObjectType, Oid};
+use std::collections::HashMap;
+use git2::{Repository, ObjectType, Oid, Sort};
 
 /// Check if a tag exists in the repository
 pub fn tag_exists(repo: &Repository, tag_name: &str) -> bool {
@@ -27,4 +28,49 @@ pub fn get_commit_from_tag(repo: &Repository, tag_name: &str) -> Option<Oid> {
         },
         Err(_) => None,
     }
+}
+
+/// Find the most recently tagged commit reachable from HEAD.
+pub fn find_latest_tag(repo: &Repository) -> Option<(String, Oid)> {
+    let tag_names = repo.tag_names(None).ok()?;
+    let mut tags_by_commit: HashMap<Oid, String> = HashMap::new();
+    for name in tag_names.iter().flatten() {
+        if let Some(commit_id) = get_commit_from_tag(repo, name) {
+            tags_by_commit.insert(commit_id, name.to_string());
+        }
+    }
+
+    let mut revwalk = repo.revwalk().ok()?;
+    revwalk.push_head().ok()?;
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME).ok()?;
+
+    for commit_id in revwalk {
+        if let Ok(oid) = commit_id {
+            if let Some(name) = tags_by_commit.get(&oid) {
+                return Some((name.clone(), oid));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{merge_repo_with_sibling_tags, merge_repo_with_tags};
+
+    #[test]
+    fn finds_newest_tag_across_a_merge() {
+        let fixture = merge_repo_with_tags();
+        let (name, _) = find_latest_tag(&fixture.repo).expect("Expected a tag to be found");
+        assert_eq!(name, "v1.1.0");
+    }
+
+    #[test]
+    fn finds_newest_tag_between_sibling_branches() {
+        let fixture = merge_repo_with_sibling_tags();
+        let (name, _) = find_latest_tag(&fixture.repo).expect("Expected a tag to be found");
+        assert_eq!(name, "v1.1.0", "the chronologically newer sibling's tag should win");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,95 @@
+use colored::*;
+use git2::{DescribeOptions, Repository, StatusOptions};
+
+/// Report working-tree state, branch, and ahead/behind counts.
+pub fn show_status(repo: &Repository) {
+    let mut status_opts = StatusOptions::new();
+    status_opts.include_untracked(true);
+
+    let statuses = match repo.statuses(Some(&mut status_opts)) {
+        Ok(statuses) => statuses,
+        Err(e) => {
+            println!("{}", format!("❌ Failed to read working tree status: {}", e).red());
+            return;
+        }
+    };
+
+    let mut staged = 0;
+    let mut modified = 0;
+    let mut untracked = 0;
+    let mut conflicted = 0;
+
+    for entry in statuses.iter() {
+        let status = entry.status();
+
+        if status.is_conflicted() {
+            conflicted += 1;
+            continue;
+        }
+        if status.is_index_new() || status.is_index_modified() || status.is_index_deleted()
+            || status.is_index_renamed() || status.is_index_typechange() {
+            staged += 1;
+        }
+        if status.is_wt_modified() || status.is_wt_deleted() || status.is_wt_renamed() || status.is_wt_typechange() {
+            modified += 1;
+        }
+        if status.is_wt_new() {
+            untracked += 1;
+        }
+    }
+
+    let head = match repo.head() {
+        Ok(head) => head,
+        Err(e) => {
+            println!("{}", format!("❌ Failed to resolve HEAD: {}", e).red());
+            return;
+        }
+    };
+
+    let mut summary = if repo.head_detached().unwrap_or(false) {
+        let short_oid = head.target().map(|oid| oid.to_string()[..7].to_string()).unwrap_or_else(|| "unknown".to_string());
+        format!("HEAD detached at {}", short_oid.cyan())
+    } else {
+        let branch_name = head.shorthand().unwrap_or("HEAD").to_string();
+        format!("on branch {}", branch_name.cyan())
+    };
+
+    if let (Some(head_ref_name), Some(local_oid)) = (head.name(), head.target()) {
+        if let Ok(upstream_buf) = repo.branch_upstream_name(head_ref_name) {
+            if let Some(upstream_ref) = upstream_buf.as_str() {
+                if let Ok(upstream_oid) = repo.refname_to_id(upstream_ref) {
+                    if let Ok((ahead, behind)) = repo.graph_ahead_behind(local_oid, upstream_oid) {
+                        let upstream_name = upstream_ref.trim_start_matches("refs/remotes/");
+                        summary.push_str(&format!(", {} ahead / {} behind {}", ahead, behind, upstream_name));
+                    }
+                }
+            }
+        }
+    }
+
+    if staged > 0 {
+        summary.push_str(&format!(", {} staged", staged));
+    }
+    if modified > 0 {
+        summary.push_str(&format!(", {} modified", modified));
+    }
+    if untracked > 0 {
+        summary.push_str(&format!(", {} untracked", untracked));
+    }
+    if conflicted > 0 {
+        summary.push_str(&format!(", {} conflicted", conflicted));
+    }
+    if staged == 0 && modified == 0 && untracked == 0 && conflicted == 0 {
+        summary.push_str(", working tree clean");
+    }
+
+    let mut describe_opts = DescribeOptions::new();
+    describe_opts.describe_tags();
+    if let Ok(describe) = repo.describe(&describe_opts) {
+        if let Ok(description) = describe.format(None) {
+            summary.push_str(&format!(", nearest tag {}", description));
+        }
+    }
+
+    println!("{}", summary);
+}
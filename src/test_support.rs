@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use git2::{ObjectType, Oid, Repository, Signature, Time};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A scratch repository for tests, deleted from disk when dropped.
+pub struct TestRepo {
+    pub repo: Repository,
+    dir: PathBuf,
+}
+
+impl Drop for TestRepo {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn signature_at(seconds_offset: i64) -> Signature<'static> {
+    let base = 1_700_000_000; // arbitrary fixed epoch so fixtures are deterministic
+    let time = Time::new(base + seconds_offset, 0);
+    Signature::new("Test Author", "test@example.com", &time).expect("Failed to build signature")
+}
+
+fn commit_blob_at(repo: &Repository, content: &str, parents: &[&git2::Commit], message: &str, update_ref: Option<&str>, seconds_offset: i64) -> Oid {
+    commit_blob_with_sig(repo, content, parents, message, update_ref, &signature_at(seconds_offset))
+}
+
+fn commit_blob_with_sig(repo: &Repository, content: &str, parents: &[&git2::Commit], message: &str, update_ref: Option<&str>, sig: &Signature) -> Oid {
+    let blob_oid = repo.blob(content.as_bytes()).expect("Failed to write blob");
+    let mut builder = repo.treebuilder(None).expect("Failed to create treebuilder");
+    builder.insert("file.txt", blob_oid, 0o100644).expect("Failed to insert blob");
+    let tree_oid = builder.write().expect("Failed to write tree");
+    let tree = repo.find_tree(tree_oid).expect("Failed to find tree");
+    repo.commit(update_ref, sig, sig, message, &tree, parents)
+        .expect("Failed to create commit")
+}
+
+fn tag(repo: &Repository, name: &str, oid: Oid) {
+    let object = repo.find_object(oid, Some(ObjectType::Commit)).expect("Failed to find commit object");
+    repo.tag_lightweight(name, &object, false).expect("Failed to create tag");
+}
+
+/// Build a repo with one merge commit and two tags — `v1.0.0` on the root
+/// commit, `v1.1.0` on the merge — to exercise history walks that must not
+/// assume a linear, chronological commit order. `side` is committed before
+/// `main` chronologically even though `main` is the merge's first parent, so a walk that
+/// orders siblings by commit time (not just topology) is required to tell
+/// them apart.
+pub fn merge_repo_with_tags() -> TestRepo {
+    let dir = std::env::temp_dir().join(format!(
+        "rustygit-test-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    ));
+    let repo = Repository::init(&dir).expect("Failed to init repo");
+
+    let root_oid = commit_blob_at(&repo, "root", &[], "feat: first", Some("HEAD"), 0);
+    tag(&repo, "v1.0.0", root_oid);
+    let root = repo.find_commit(root_oid).expect("Failed to find root commit");
+
+    // Built on a ref of its own so it doesn't clobber HEAD/main below, and
+    // committed chronologically before `main` despite being parented first.
+    let side_oid = commit_blob_at(&repo, "side", &[&root], "fix: third", None, 86_400);
+    repo.reference("refs/heads/side", side_oid, true, "test: create side branch")
+        .expect("Failed to create side branch");
+    let side = repo.find_commit(side_oid).expect("Failed to find side commit");
+
+    let main_oid = commit_blob_at(&repo, "main", &[&root], "feat: second", Some("HEAD"), 2 * 86_400);
+    let main = repo.find_commit(main_oid).expect("Failed to find main commit");
+
+    let merge_oid = commit_blob_at(&repo, "merge", &[&main, &side], "feat: merge side into main", Some("HEAD"), 3 * 86_400);
+    tag(&repo, "v1.1.0", merge_oid);
+    drop(root);
+    drop(main);
+    drop(side);
+
+    TestRepo { repo, dir }
+}
+
+/// Build a repo with tags on *both* sibling branches of a merge — `v1.0.0`
+/// on the chronologically older `side` commit, `v1.1.0` on the
+/// chronologically newer `main` commit — so "find the most recently tagged
+/// commit" can only be answered correctly by ordering siblings by commit
+/// time, not merely by a topological tie-break.
+pub fn merge_repo_with_sibling_tags() -> TestRepo {
+    let dir = std::env::temp_dir().join(format!(
+        "rustygit-test-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    ));
+    let repo = Repository::init(&dir).expect("Failed to init repo");
+
+    let root_oid = commit_blob_at(&repo, "root", &[], "feat: first", Some("HEAD"), 0);
+    let root = repo.find_commit(root_oid).expect("Failed to find root commit");
+
+    let side_oid = commit_blob_at(&repo, "side", &[&root], "fix: second", None, 86_400);
+    repo.reference("refs/heads/side", side_oid, true, "test: create side branch")
+        .expect("Failed to create side branch");
+    tag(&repo, "v1.0.0", side_oid);
+    let side = repo.find_commit(side_oid).expect("Failed to find side commit");
+
+    let main_oid = commit_blob_at(&repo, "main", &[&root], "feat: third", Some("HEAD"), 2 * 86_400);
+    tag(&repo, "v1.1.0", main_oid);
+    let main = repo.find_commit(main_oid).expect("Failed to find main commit");
+
+    commit_blob_at(&repo, "merge", &[&main, &side], "feat: merge side into main", Some("HEAD"), 3 * 86_400);
+    drop(root);
+    drop(main);
+    drop(side);
+
+    TestRepo { repo, dir }
+}
@@ -0,0 +1,203 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+use colored::*;
+use git2::{DiffOptions, ObjectType, Oid, Repository, Sort, TreeWalkMode, TreeWalkResult};
+use serde::{Deserialize, Serialize};
+
+/// Aggregate repository-health metrics recorded for a single commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitMetrics {
+    pub commit_order: usize,
+    pub author: String,
+    pub total_files: usize,
+    pub total_lines: usize,
+    pub lines_added: usize,
+    pub lines_deleted: usize,
+    pub commits_by_author: HashMap<String, usize>,
+}
+
+/// The on-disk TOML history: a `HashMap<commit_hash, CommitMetrics>`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MetricsHistory {
+    #[serde(default)]
+    commits: HashMap<String, CommitMetrics>,
+}
+
+/// Record aggregate metrics for each commit reachable from HEAD, appending new ones to `output`.
+pub fn track(repo: &Repository, output: &str) {
+    let mut history = load_history(output);
+
+    let mut revwalk = repo.revwalk().expect("Failed to create revwalk");
+    revwalk.push_head().expect("Failed to push head");
+    revwalk
+        .set_sorting(Sort::TOPOLOGICAL | Sort::TIME)
+        .expect("Failed to set revwalk sorting");
+
+    // Topological+time order is newest-first with every commit preceding its
+    // parents and siblings broken by commit time; reverse it so commit_order
+    // and the cumulative commits-per-author map walk chronologically.
+    let mut oids: Vec<Oid> = revwalk.filter_map(Result::ok).collect();
+    oids.reverse();
+
+    let mut commits_by_author: HashMap<String, usize> = HashMap::new();
+    let mut recorded = 0usize;
+
+    for (order, oid) in oids.into_iter().enumerate() {
+        let commit = match repo.find_commit(oid) {
+            Ok(commit) => commit,
+            Err(_) => continue,
+        };
+
+        let author = commit.author().name().unwrap_or("Unknown").to_string();
+        *commits_by_author.entry(author.clone()).or_insert(0) += 1;
+
+        let hash = oid.to_string();
+        if history.commits.contains_key(&hash) {
+            continue;
+        }
+
+        let tree = commit.tree().expect("Failed to get commit tree");
+        let (total_files, total_lines) = tree_stats(repo, &tree);
+
+        let parent = commit.parent(0).ok();
+        let mut diff_opts = DiffOptions::new();
+        let diff = match &parent {
+            Some(parent) => repo
+                .diff_tree_to_tree(Some(&parent.tree().unwrap()), Some(&tree), Some(&mut diff_opts))
+                .expect("Failed to diff trees"),
+            None => repo
+                .diff_tree_to_tree(None, Some(&tree), Some(&mut diff_opts))
+                .expect("Failed to diff trees"),
+        };
+        let stats = diff.stats().expect("Failed to get diff stats");
+
+        history.commits.insert(hash, CommitMetrics {
+            commit_order: order + 1,
+            author,
+            total_files,
+            total_lines,
+            lines_added: stats.insertions(),
+            lines_deleted: stats.deletions(),
+            commits_by_author: commits_by_author.clone(),
+        });
+        recorded += 1;
+    }
+
+    match save_history(output, &history) {
+        Ok(()) => println!("✅ Recorded {} new commit snapshot(s) to '{}'", recorded, output),
+        Err(e) => println!("{}", format!("❌ Failed to write '{}': {}", output, e).red()),
+    }
+}
+
+/// Print each recorded metric in commit order, alongside its delta from the previous commit.
+pub fn report(output: &str) {
+    let history = load_history(output);
+    if history.commits.is_empty() {
+        println!("{}", format!("No metrics recorded in '{}' yet.", output).yellow());
+        return;
+    }
+
+    let mut records: Vec<(&String, &CommitMetrics)> = history.commits.iter().collect();
+    records.sort_by_key(|(_, metrics)| metrics.commit_order);
+
+    let mut previous: Option<&CommitMetrics> = None;
+    for (hash, metrics) in records {
+        let total_commits: usize = metrics.commits_by_author.values().sum();
+        println!("\n{} (#{}) by {}", &hash[..7], metrics.commit_order, metrics.author);
+        print_delta("Total files", metrics.total_files as i64, previous.map(|p| p.total_files as i64));
+        print_delta("Total lines", metrics.total_lines as i64, previous.map(|p| p.total_lines as i64));
+        print_delta("Lines added", metrics.lines_added as i64, previous.map(|p| p.lines_added as i64));
+        print_delta("Lines deleted", metrics.lines_deleted as i64, previous.map(|p| p.lines_deleted as i64));
+        print_delta("Total commits", total_commits as i64, previous.map(|p| p.commits_by_author.values().sum::<usize>() as i64));
+        previous = Some(metrics);
+    }
+}
+
+fn print_delta(label: &str, value: i64, previous: Option<i64>) {
+    match previous {
+        Some(prev) => {
+            let delta = value - prev;
+            let rendered = match delta.cmp(&0) {
+                Ordering::Greater => format!("+{}", delta).green(),
+                Ordering::Less => delta.to_string().red(),
+                Ordering::Equal => "±0".normal(),
+            };
+            println!("  {}: {} ({})", label, value, rendered);
+        }
+        None => println!("  {}: {}", label, value),
+    }
+}
+
+fn tree_stats(repo: &Repository, tree: &git2::Tree) -> (usize, usize) {
+    let mut total_files = 0usize;
+    let mut total_lines = 0usize;
+
+    let _ = tree.walk(TreeWalkMode::PreOrder, |_root, entry| {
+        if entry.kind() == Some(ObjectType::Blob) {
+            total_files += 1;
+            if let Ok(object) = entry.to_object(repo) {
+                if let Some(blob) = object.as_blob() {
+                    if !blob.is_binary() {
+                        total_lines += String::from_utf8_lossy(blob.content()).lines().count();
+                    }
+                }
+            }
+        }
+        TreeWalkResult::Ok
+    });
+
+    (total_files, total_lines)
+}
+
+fn load_history(path: &str) -> MetricsHistory {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).unwrap_or_default(),
+        Err(_) => MetricsHistory::default(),
+    }
+}
+
+fn save_history(path: &str, history: &MetricsHistory) -> Result<(), String> {
+    let serialized = toml::to_string_pretty(history).map_err(|e| e.to_string())?;
+    fs::write(path, serialized).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repo::get_commit_from_tag;
+    use crate::test_support::merge_repo_with_tags;
+
+    #[test]
+    fn orders_commits_chronologically_across_a_merge() {
+        let fixture = merge_repo_with_tags();
+        let root_oid = get_commit_from_tag(&fixture.repo, "v1.0.0").expect("Expected v1.0.0 to resolve");
+        let merge_oid = get_commit_from_tag(&fixture.repo, "v1.1.0").expect("Expected v1.1.0 to resolve");
+
+        let output = std::env::temp_dir().join(format!("rustygit-track-test-{}.toml", std::process::id()));
+        let output = output.to_str().unwrap();
+
+        track(&fixture.repo, output);
+        let history = load_history(output);
+        let _ = fs::remove_file(output);
+
+        let merge_commit = fixture.repo.find_commit(merge_oid).expect("Failed to find merge commit");
+        let main_oid = merge_commit.parent_id(0).expect("Expected merge to have a main parent");
+        let side_oid = merge_commit.parent_id(1).expect("Expected merge to have a side parent");
+
+        let root = &history.commits[&root_oid.to_string()];
+        let side = &history.commits[&side_oid.to_string()];
+        let main = &history.commits[&main_oid.to_string()];
+        let merge = &history.commits[&merge_oid.to_string()];
+
+        assert_eq!(root.commit_order, 1, "root commit should be recorded first");
+        assert_eq!(merge.commit_order, 4, "merge commit should be recorded last");
+        assert!(
+            side.commit_order < main.commit_order,
+            "side was committed before main chronologically and should be ordered first, got side={}, main={}",
+            side.commit_order,
+            main.commit_order
+        );
+        assert_eq!(merge.commits_by_author.values().sum::<usize>(), 4);
+    }
+}
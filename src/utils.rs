@@ -1,12 +1,13 @@
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
 
-/// Format a git time to a readable string
+/// Format a git time to a readable string, respecting the commit's own
+/// timezone offset.
 pub fn format_time(time: &git2::Time) -> String {
-    let seconds = time.seconds();
-    let offset = time.offset_minutes();
-    
-    let timestamp = seconds + (offset as i64 * 60);
-    format!("{}", timestamp)
+    let offset = FixedOffset::east_opt(time.offset_minutes() * 60).unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+    match offset.timestamp_opt(time.seconds(), 0) {
+        chrono::LocalResult::Single(datetime) => format!("{}", datetime.format("%d %b %Y %H:%M:%S %z")),
+        _ => format!("{}", time.seconds()),
+    }
 }
 
 /// Format a timestamp to a readable string
@@ -14,3 +15,153 @@ pub fn format_timestamp(timestamp: i64) -> String {
     let datetime: DateTime<Utc> = Utc.timestamp_opt(timestamp, 0).unwrap();
     format!("{}", datetime.format("%d %b %Y %H:%M:%S"))
 }
+
+/// Render a git time as a human-relative string, e.g. "3 days ago", diffing
+/// against the current moment and formatting the largest applicable unit.
+pub fn format_relative_time(time: &git2::Time) -> String {
+    relative_time_from(time.seconds(), Utc::now().timestamp())
+}
+
+fn relative_time_from(seconds_epoch: i64, now: i64) -> String {
+    let mut seconds = now - seconds_epoch;
+    let future = seconds < 0;
+    if future {
+        seconds = -seconds;
+    }
+
+    let (amount, unit) = if seconds < 60 {
+        (seconds, "second")
+    } else if seconds < 60 * 60 {
+        (seconds / 60, "minute")
+    } else if seconds < 60 * 60 * 24 {
+        (seconds / (60 * 60), "hour")
+    } else if seconds < 60 * 60 * 24 * 30 {
+        (seconds / (60 * 60 * 24), "day")
+    } else if seconds < 60 * 60 * 24 * 365 {
+        (seconds / (60 * 60 * 24 * 30), "month")
+    } else {
+        (seconds / (60 * 60 * 24 * 365), "year")
+    };
+
+    let unit = if amount == 1 { unit.to_string() } else { format!("{}s", unit) };
+    if future {
+        format!("in {} {}", amount, unit)
+    } else {
+        format!("{} {} ago", amount, unit)
+    }
+}
+
+/// A `--since`/`--until` commit date filter, parsed from `YYYY-MM-DD` strings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateRange {
+    since: Option<i64>,
+    /// Exclusive upper bound (the instant just after the `--until` day ends).
+    until: Option<i64>,
+}
+
+impl DateRange {
+    /// Parse `--since`/`--until` options into a `DateRange`. Returns an
+    /// error message naming the offending value if either fails to parse.
+    pub fn parse(since: &Option<String>, until: &Option<String>) -> Result<DateRange, String> {
+        let since = since.as_deref().map(parse_date_bound).transpose()?;
+        let until = until
+            .as_deref()
+            .map(parse_date_bound)
+            .transpose()?
+            .map(|ts| ts + 24 * 60 * 60);
+
+        Ok(DateRange { since, until })
+    }
+
+    /// Whether a commit's epoch seconds fall inside this range.
+    pub fn contains(&self, seconds: i64) -> bool {
+        self.since.is_none_or(|since| seconds >= since) && self.until.is_none_or(|until| seconds < until)
+    }
+
+    /// Whether this range has no `--since`/`--until` bound at all.
+    pub fn is_unbounded(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+}
+
+fn parse_date_bound(date: &str) -> Result<i64, String> {
+    let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| format!("invalid date '{}', expected YYYY-MM-DD", date))?;
+    let start_of_day = parsed.and_hms_opt(0, 0, 0).expect("midnight is always a valid time");
+    Ok(start_of_day.and_utc().timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_time_just_under_a_minute_is_seconds() {
+        assert_eq!(relative_time_from(1_000, 1_059), "59 seconds ago");
+    }
+
+    #[test]
+    fn relative_time_at_a_minute_rolls_over_to_minutes() {
+        assert_eq!(relative_time_from(1_000, 1_060), "1 minute ago");
+    }
+
+    #[test]
+    fn relative_time_pluralizes_minutes() {
+        assert_eq!(relative_time_from(0, 120), "2 minutes ago");
+    }
+
+    #[test]
+    fn relative_time_just_under_thirty_days_is_days() {
+        let seconds = 29 * 24 * 60 * 60;
+        assert_eq!(relative_time_from(0, seconds as i64), "29 days ago");
+    }
+
+    #[test]
+    fn relative_time_at_thirty_days_rolls_over_to_months() {
+        let seconds = 30 * 24 * 60 * 60;
+        assert_eq!(relative_time_from(0, seconds as i64), "1 month ago");
+    }
+
+    #[test]
+    fn relative_time_future_is_phrased_as_in() {
+        assert_eq!(relative_time_from(1_060, 1_000), "in 1 minute");
+    }
+
+    #[test]
+    fn relative_time_singular_unit_has_no_trailing_s() {
+        assert_eq!(relative_time_from(0, 1), "1 second ago");
+    }
+
+    #[test]
+    fn date_range_parse_defaults_to_unbounded() {
+        let range = DateRange::parse(&None, &None).unwrap();
+        assert!(range.is_unbounded());
+        assert!(range.contains(0));
+        assert!(range.contains(i64::MAX));
+    }
+
+    #[test]
+    fn date_range_since_is_inclusive() {
+        let since_ts = parse_date_bound("2024-06-01").unwrap();
+        let range = DateRange::parse(&Some("2024-06-01".to_string()), &None).unwrap();
+        assert!(!range.is_unbounded());
+        assert!(range.contains(since_ts));
+        assert!(!range.contains(since_ts - 1));
+    }
+
+    #[test]
+    fn date_range_until_is_an_exclusive_day_boundary() {
+        let until_day_start = parse_date_bound("2024-06-30").unwrap();
+        let range = DateRange::parse(&None, &Some("2024-06-30".to_string())).unwrap();
+
+        assert!(range.contains(until_day_start), "the whole --until day should be included");
+        assert!(range.contains(until_day_start + 24 * 60 * 60 - 1), "the last second of the --until day should be included");
+        assert!(!range.contains(until_day_start + 24 * 60 * 60), "the instant after the --until day should be excluded");
+    }
+
+    #[test]
+    fn date_range_parse_rejects_malformed_date() {
+        let err = DateRange::parse(&Some("not-a-date".to_string()), &None).unwrap_err();
+        assert!(err.contains("not-a-date"));
+    }
+}